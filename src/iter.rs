@@ -0,0 +1,51 @@
+use num_traits::{One, SaturatingAdd};
+
+use crate::OpenRange;
+
+/// An iterator over the integer values contained in a range.
+///
+/// Modelled after the `RangeInclusive` redesign: there is no separate
+/// "exhausted" flag, since exhaustion is just `cursor >= end`. When `end` is
+/// `None` the iterator is unbounded and yields forever, saturating at
+/// `Idx::MAX` instead of overflowing.
+#[derive(Clone, Debug)]
+pub struct RangeIter<Idx> {
+    cursor: Idx,
+    end: Option<Idx>,
+}
+
+impl<Idx> RangeIter<Idx> {
+    #[inline]
+    #[must_use]
+    pub fn new(start: Idx, end: Option<Idx>) -> Self {
+        Self { cursor: start, end }
+    }
+}
+
+impl<Idx: Clone + Ord + One + SaturatingAdd> Iterator for RangeIter<Idx> {
+    type Item = Idx;
+
+    #[allow(clippy::collapsible_if)]
+    fn next(&mut self) -> Option<Idx> {
+        if let Some(end) = &self.end {
+            if &self.cursor >= end {
+                return None;
+            }
+        }
+
+        let current = self.cursor.clone();
+        self.cursor = self.cursor.saturating_add(&One::one());
+
+        Some(current)
+    }
+}
+
+impl<Idx: Clone + Ord + One + SaturatingAdd> IntoIterator for OpenRange<Idx> {
+    type Item = Idx;
+    type IntoIter = RangeIter<Idx>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        RangeIter::new(self.start, self.end)
+    }
+}
@@ -1,6 +1,12 @@
 use std::ops::{self, RangeToInclusive};
 
-use num_traits::{One, SaturatingAdd, Zero};
+use num_traits::{One, SaturatingAdd, SaturatingSub, Zero};
+
+mod iter;
+mod range_set;
+
+pub use iter::RangeIter;
+pub use range_set::RangeSet;
 
 #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct OpenRange<Idx> {
@@ -8,6 +14,15 @@ pub struct OpenRange<Idx> {
     pub end: Option<Idx>,
 }
 
+/// Result of unioning two ranges: either they overlap or touch and merge into
+/// a single range, or a gap separates them and both halves are kept, in
+/// sorted order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RangeUnion<Idx> {
+    Single(OpenRange<Idx>),
+    Disjoint(OpenRange<Idx>, OpenRange<Idx>),
+}
+
 pub trait PartialRange<Idx: Clone + Ord = usize> {
     #[must_use]
     fn start(&self) -> Idx;
@@ -63,6 +78,101 @@ pub trait PartialRange<Idx: Clone + Ord = usize> {
             |end| self.clamp(other.start(), end).to_open_range(),
         )
     }
+
+    #[inline]
+    #[must_use]
+    fn contains(&self, value: Idx) -> bool {
+        self.start() <= value && self.end().is_none_or(|end| value < end)
+    }
+
+    #[inline]
+    #[must_use]
+    fn contains_range<T, R>(&self, other: R) -> bool
+    where
+        T: Clone + Ord + Into<Idx>,
+        R: PartialRange<T>,
+    {
+        let other_start = other.start().into();
+
+        self.start() <= other_start
+            && match (self.end(), other.end()) {
+                (None, _) => true,
+                (Some(_), None) => false,
+                (Some(self_end), Some(other_end)) => other_end.into() <= self_end,
+            }
+    }
+
+    #[inline]
+    #[must_use]
+    fn overlaps<T, R>(&self, other: R) -> bool
+    where
+        T: Clone + Ord + Into<Idx>,
+        R: PartialRange<T>,
+    {
+        if self.is_empty() || other.is_empty() {
+            return false;
+        }
+
+        let other_start: Idx = other.start().into();
+
+        let self_starts_before_other_ends = match other.end() {
+            Some(other_end) => self.start() < other_end.into(),
+            None => true,
+        };
+        let other_starts_before_self_ends = match self.end() {
+            Some(self_end) => other_start < self_end,
+            None => true,
+        };
+
+        self_starts_before_other_ends && other_starts_before_self_ends
+    }
+
+    #[inline]
+    #[must_use]
+    fn is_empty(&self) -> bool {
+        self.end().is_some_and(|end| self.start() >= end)
+    }
+
+    #[inline]
+    #[must_use]
+    fn union<T, R>(&self, other: R) -> RangeUnion<Idx>
+    where
+        T: Clone + Ord + Into<Idx>,
+        R: PartialRange<T>,
+    {
+        let self_start = self.start();
+        let self_end = self.end();
+        let other_start: Idx = other.start().into();
+        let other_end: Option<Idx> = other.end().map(Into::into);
+
+        let touches = other_end.as_ref().is_none_or(|end| self_start <= *end)
+            && self_end.as_ref().is_none_or(|end| other_start <= *end);
+
+        if touches {
+            RangeUnion::Single(OpenRange {
+                start: self_start.min(other_start),
+                end: match (self_end, other_end) {
+                    (Some(a), Some(b)) => Some(a.max(b)),
+                    _ => None,
+                },
+            })
+        } else {
+            let lhs = OpenRange {
+                start: self_start,
+                end: self_end,
+            };
+            let rhs = OpenRange {
+                start: other_start,
+                end: other_end,
+            };
+
+            if lhs.start <= rhs.start {
+                RangeUnion::Disjoint(lhs, rhs)
+            } else {
+                RangeUnion::Disjoint(rhs, lhs)
+            }
+        }
+    }
 }
 
 impl<Idx: Clone + Ord> PartialRange<Idx> for OpenRange<Idx> {
@@ -122,6 +232,128 @@ pub trait Range<Idx: Clone + Ord = usize> {
             end: Some(self.end()),
         }
     }
+
+    #[inline]
+    #[must_use]
+    fn contains(&self, value: Idx) -> bool {
+        self.start() <= value && value < self.end()
+    }
+
+    #[inline]
+    #[must_use]
+    fn contains_range<T, R>(&self, other: &R) -> bool
+    where
+        T: Clone + Ord + Into<Idx>,
+        R: Range<T>,
+    {
+        self.start() <= other.start().into() && other.end().into() <= self.end()
+    }
+
+    #[inline]
+    #[must_use]
+    fn overlaps<T, R>(&self, other: &R) -> bool
+    where
+        T: Clone + Ord + Into<Idx>,
+        R: Range<T>,
+    {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.start() < other.end().into()
+            && other.start().into() < self.end()
+    }
+
+    #[inline]
+    #[must_use]
+    fn is_empty(&self) -> bool {
+        self.start() >= self.end()
+    }
+
+    #[inline]
+    #[must_use]
+    fn union<T, R>(&self, other: &R) -> RangeUnion<Idx>
+    where
+        T: Clone + Ord + Into<Idx>,
+        R: Range<T>,
+    {
+        let self_start = self.start();
+        let self_end = self.end();
+        let other_start: Idx = other.start().into();
+        let other_end: Idx = other.end().into();
+
+        if self_start <= other_end && other_start <= self_end {
+            RangeUnion::Single(OpenRange {
+                start: self_start.min(other_start),
+                end: Some(self_end.max(other_end)),
+            })
+        } else {
+            let lhs = OpenRange {
+                start: self_start,
+                end: Some(self_end),
+            };
+            let rhs = OpenRange {
+                start: other_start,
+                end: Some(other_end),
+            };
+
+            if lhs.start <= rhs.start {
+                RangeUnion::Disjoint(lhs, rhs)
+            } else {
+                RangeUnion::Disjoint(rhs, lhs)
+            }
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    fn iter(&self) -> RangeIter<Idx>
+    where
+        Idx: One + SaturatingAdd,
+    {
+        RangeIter::new(self.start(), Some(self.end()))
+    }
+
+    #[inline]
+    #[must_use]
+    fn len(&self) -> Idx
+    where
+        Idx: Zero + ops::Sub<Output = Idx>,
+    {
+        if self.is_empty() {
+            Idx::zero()
+        } else {
+            self.end() - self.start()
+        }
+    }
+
+    /// Splits this range at `at`, clamping it into `[start, end]` first.
+    #[inline]
+    #[must_use]
+    fn split_at(&self, at: impl Into<Idx>) -> (ops::Range<Idx>, ops::Range<Idx>) {
+        let at = at.into().clamp(self.start(), self.end());
+
+        (self.start()..at.clone(), at..self.end())
+    }
+
+    /// Widens both ends of this range by `amount`, saturating at `Idx`'s
+    /// lower bound rather than underflowing when `start` is near zero.
+    #[inline]
+    #[must_use]
+    fn grow(&self, amount: Idx) -> ops::Range<Idx>
+    where
+        Idx: SaturatingAdd + SaturatingSub,
+    {
+        self.start().saturating_sub(&amount)..self.end().saturating_add(&amount)
+    }
+
+    /// Translates this range by `delta`.
+    #[inline]
+    #[must_use]
+    fn shift(&self, delta: Idx) -> ops::Range<Idx>
+    where
+        Idx: ops::Add<Output = Idx>,
+    {
+        self.start() + delta.clone()..self.end() + delta
+    }
 }
 
 impl<Idx: Clone + Ord> Range<Idx> for ops::Range<Idx> {
@@ -223,3 +455,24 @@ impl<Idx: Clone + One + Ord + SaturatingAdd + Zero> PartialRange<Idx> for RangeT
         Some(self.end.saturating_add(&One::one()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{PartialRange, Range};
+
+    #[test]
+    fn grow_saturates_instead_of_underflowing() {
+        assert_eq!(Range::grow(&(0usize..5usize), 3), 0..8);
+        assert_eq!(Range::grow(&(2usize..5usize), 3), 0..8);
+    }
+
+    #[test]
+    fn overlaps_is_false_for_an_empty_range() {
+        assert!(!Range::overlaps(&(7..7), &(2..8)));
+        assert!(!Range::overlaps(&(2..8), &(7..7)));
+        assert!(!Range::overlaps(&(7..7), &(7..7)));
+
+        assert!(!PartialRange::overlaps(&(7..7), 2..8));
+        assert!(!PartialRange::overlaps(&(2..8), 7..7));
+    }
+}
@@ -0,0 +1,433 @@
+use std::ops;
+
+use crate::Range;
+
+/// A sorted, non-overlapping (SNO) collection of [`ops::Range`]s.
+///
+/// The backing `Vec` is always kept sorted by `start`, and adjacent or
+/// overlapping ranges are coalesced, so the set has a single canonical
+/// representation for any given collection of covered indices.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeSet<Idx> {
+    ranges: Vec<ops::Range<Idx>>,
+}
+
+impl<Idx: Clone + Ord> RangeSet<Idx> {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// The normalized, sorted, non-overlapping ranges backing this set.
+    #[inline]
+    #[must_use]
+    pub fn ranges(&self) -> &[ops::Range<Idx>] {
+        &self.ranges
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: &Idx) -> bool {
+        self.ranges
+            .iter()
+            .any(|range| &range.start <= value && value < &range.end)
+    }
+
+    pub fn insert(&mut self, range: ops::Range<Idx>) {
+        self.ranges = merge(std::mem::take(&mut self.ranges), vec![range]);
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            ranges: merge(self.ranges.clone(), other.ranges.clone()),
+        }
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self {
+            ranges: intersect(&self.ranges, &other.ranges),
+        }
+    }
+
+    /// `self` minus `other`: every sub-range covered by `self` but not by `other`.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        Self {
+            ranges: difference(&self.ranges, &other.ranges),
+        }
+    }
+
+    #[must_use]
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        Self {
+            ranges: merge(
+                difference(&self.ranges, &other.ranges),
+                difference(&other.ranges, &self.ranges),
+            ),
+        }
+    }
+}
+
+impl<Idx: Clone + Ord> From<Vec<ops::Range<Idx>>> for RangeSet<Idx> {
+    fn from(ranges: Vec<ops::Range<Idx>>) -> Self {
+        Self {
+            ranges: merge(ranges, Vec::new()),
+        }
+    }
+}
+
+impl<Idx: Clone + Ord> FromIterator<ops::Range<Idx>> for RangeSet<Idx> {
+    fn from_iter<T: IntoIterator<Item = ops::Range<Idx>>>(iter: T) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+/// Merges two collections of ranges into a single sorted, coalesced `Vec`.
+fn merge<Idx: Clone + Ord>(
+    a: Vec<ops::Range<Idx>>,
+    b: Vec<ops::Range<Idx>>,
+) -> Vec<ops::Range<Idx>> {
+    let mut all = a;
+    all.extend(b);
+    all.sort_by(|lhs, rhs| lhs.start.cmp(&rhs.start));
+
+    let mut merged: Vec<ops::Range<Idx>> = Vec::with_capacity(all.len());
+
+    for range in all {
+        if range.start >= range.end {
+            continue;
+        }
+
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => {
+                if range.end > last.end {
+                    last.end = range.end;
+                }
+            }
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+}
+
+/// Two-pointer sweep over two SNO slices, yielding their pairwise intersections.
+fn intersect<Idx: Clone + Ord>(
+    a: &[ops::Range<Idx>],
+    b: &[ops::Range<Idx>],
+) -> Vec<ops::Range<Idx>> {
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        let clamped = Range::intersection(&a[i], &b[j]);
+
+        if clamped.start < clamped.end {
+            out.push(clamped);
+        }
+
+        if a[i].end < b[j].end {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    out
+}
+
+/// `a` minus `b`: walks `a`'s ranges, clipping out every overlapping `b` range.
+fn difference<Idx: Clone + Ord>(
+    a: &[ops::Range<Idx>],
+    b: &[ops::Range<Idx>],
+) -> Vec<ops::Range<Idx>> {
+    let mut out = Vec::new();
+
+    for range in a {
+        let mut cursor = range.start.clone();
+
+        for other in b {
+            if other.end <= cursor || other.start >= range.end {
+                continue;
+            }
+
+            if other.start > cursor {
+                out.push(cursor.clone()..other.start.clone());
+            }
+
+            if other.end > cursor {
+                cursor = other.end.clone();
+            }
+        }
+
+        if cursor < range.end {
+            out.push(cursor..range.end.clone());
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RangeSet;
+
+    #[test]
+    fn insert_coalesces_touching_and_overlapping_ranges() {
+        let mut set = RangeSet::new();
+        set.insert(0..5);
+        set.insert(5..10);
+        set.insert(8..12);
+        set.insert(20..25);
+
+        assert_eq!(set.ranges(), [0..12, 20..25]);
+    }
+
+    #[test]
+    fn contains_checks_membership() {
+        let set: RangeSet<i32> = vec![0..5, 10..15].into();
+
+        assert!(set.contains(&3));
+        assert!(!set.contains(&5));
+        assert!(!set.contains(&7));
+        assert!(set.contains(&10));
+    }
+
+    fn single_range(start: i32, end: i32) -> RangeSet<i32> {
+        let mut set = RangeSet::new();
+        set.insert(start..end);
+        set
+    }
+
+    #[test]
+    fn union_merges_overlapping_and_keeps_disjoint_ranges() {
+        let a: RangeSet<i32> = vec![0..10, 20..30].into();
+        let b = single_range(5, 25);
+
+        assert_eq!(a.union(&b), single_range(0, 30));
+        assert_eq!(
+            a.union(&single_range(12, 15)).ranges(),
+            [0..10, 12..15, 20..30]
+        );
+    }
+
+    #[test]
+    fn intersection_keeps_only_overlapping_parts() {
+        let a: RangeSet<i32> = vec![0..10, 20..30].into();
+        let b = single_range(5, 25);
+
+        assert_eq!(a.intersection(&b).ranges(), [5..10, 20..25]);
+    }
+
+    #[test]
+    fn difference_removes_overlapping_parts() {
+        let a: RangeSet<i32> = vec![0..10, 20..30].into();
+        let b = single_range(5, 25);
+
+        assert_eq!(a.difference(&b).ranges(), [0..5, 25..30]);
+    }
+
+    #[test]
+    fn symmetric_difference_keeps_parts_unique_to_either_side() {
+        let a: RangeSet<i32> = vec![0..10, 20..30].into();
+        let b = single_range(5, 25);
+
+        assert_eq!(
+            a.symmetric_difference(&b).ranges(),
+            [0..5, 10..20, 25..30]
+        );
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod rayon_support {
+    use std::ops;
+
+    use rayon::prelude::*;
+
+    use super::RangeSet;
+
+    impl<Idx: Clone + Ord + Send + Sync> RangeSet<Idx> {
+        #[must_use]
+        pub fn par_overlaps(&self, range: &ops::Range<Idx>) -> bool {
+            self.ranges
+                .par_iter()
+                .any(|x| !(x.start >= range.end || x.end <= range.start))
+        }
+
+        #[must_use]
+        pub fn par_intersection(&self, other: &Self) -> Self {
+            Self {
+                ranges: par_chunked(&self.ranges, &other.ranges, |a, b| super::intersect(a, b)),
+            }
+        }
+
+        /// Unlike `par_intersection`, a union cannot drop a range just because
+        /// it doesn't overlap anything on the other side, so this cannot
+        /// reuse `par_chunked`'s "slice of `b` overlapping this `a` chunk"
+        /// split: an isolated `b` range would fall outside every chunk's
+        /// hull and be silently lost. Instead this splits on connected
+        /// components of `self` and `other` combined, which is safe because
+        /// every range belongs to exactly one component.
+        #[must_use]
+        pub fn par_union(&self, other: &Self) -> Self {
+            let mut ranges: Vec<ops::Range<Idx>> = components(&self.ranges, &other.ranges)
+                .into_par_iter()
+                .flat_map(|(a_chunk, b_chunk)| super::merge(a_chunk.to_vec(), b_chunk.to_vec()))
+                .collect();
+
+            ranges.sort_by(|lhs, rhs| lhs.start.cmp(&rhs.start));
+
+            Self { ranges }
+        }
+    }
+
+    /// Splits `a` into independent chunks on interval boundaries, matches each
+    /// chunk with the slice of `b` it could possibly interact with, runs `op`
+    /// on each pair in parallel, then concatenates and coalesces the results.
+    fn par_chunked<Idx, F>(
+        a: &[ops::Range<Idx>],
+        b: &[ops::Range<Idx>],
+        op: F,
+    ) -> Vec<ops::Range<Idx>>
+    where
+        Idx: Clone + Ord + Send + Sync,
+        F: Fn(&[ops::Range<Idx>], &[ops::Range<Idx>]) -> Vec<ops::Range<Idx>> + Sync,
+    {
+        if a.is_empty() || b.is_empty() {
+            return op(a, b);
+        }
+
+        let chunk_count = rayon::current_num_threads().max(1);
+        let chunk_size = a.len().div_ceil(chunk_count).max(1);
+
+        let chunks: Vec<_> = a
+            .chunks(chunk_size)
+            .map(|a_chunk| {
+                let lo = a_chunk.first().unwrap().start.clone();
+                let hi = a_chunk.last().unwrap().end.clone();
+
+                let start = b.partition_point(|r| r.end <= lo);
+                let end = b.partition_point(|r| r.start < hi);
+
+                (a_chunk, &b[start..end])
+            })
+            .collect();
+
+        let mut result: Vec<ops::Range<Idx>> = chunks
+            .into_par_iter()
+            .flat_map(|(a_chunk, b_chunk)| op(a_chunk, b_chunk))
+            .collect();
+
+        result.sort_by(|lhs, rhs| lhs.start.cmp(&rhs.start));
+
+        super::merge(result, Vec::new())
+    }
+
+    type Component<'a, Idx> = (&'a [ops::Range<Idx>], &'a [ops::Range<Idx>]);
+
+    /// Walks `a` and `b` together (both already SNO) and splits them into
+    /// connected components: maximal runs of mutually overlapping-or-touching
+    /// ranges from either side. Every range from `a` and `b` lands in
+    /// exactly one component, and components never interact with each other,
+    /// so each pair of slices can be unioned independently and in parallel.
+    fn components<'a, Idx: Clone + Ord>(
+        a: &'a [ops::Range<Idx>],
+        b: &'a [ops::Range<Idx>],
+    ) -> Vec<Component<'a, Idx>> {
+        let mut out = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < a.len() || j < b.len() {
+            let (a_start, b_start) = (i, j);
+
+            let mut end = match (a.get(i), b.get(j)) {
+                (Some(ar), Some(br)) if ar.start <= br.start => {
+                    i += 1;
+                    ar.end.clone()
+                }
+                (Some(ar), None) => {
+                    i += 1;
+                    ar.end.clone()
+                }
+                _ => {
+                    j += 1;
+                    b[j - 1].end.clone()
+                }
+            };
+
+            loop {
+                let from_a = a.get(i).filter(|r| r.start <= end);
+                let from_b = b.get(j).filter(|r| r.start <= end);
+
+                match (from_a, from_b) {
+                    (Some(ar), Some(br)) if ar.end >= br.end => {
+                        end = end.max(ar.end.clone());
+                        i += 1;
+                    }
+                    (Some(_), Some(br)) => {
+                        end = end.max(br.end.clone());
+                        j += 1;
+                    }
+                    (Some(ar), None) => {
+                        end = end.max(ar.end.clone());
+                        i += 1;
+                    }
+                    (None, Some(br)) => {
+                        end = end.max(br.end.clone());
+                        j += 1;
+                    }
+                    (None, None) => break,
+                }
+            }
+
+            out.push((&a[a_start..i], &b[b_start..j]));
+        }
+
+        out
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::RangeSet;
+
+        #[test]
+        fn par_union_keeps_isolated_ranges() {
+            let mut a = RangeSet::new();
+            a.insert(0..5);
+
+            let mut b = RangeSet::new();
+            b.insert(100..105);
+
+            assert_eq!(a.par_union(&b).ranges(), a.union(&b).ranges());
+            assert_eq!(a.par_union(&b).ranges(), [0..5, 100..105]);
+        }
+
+        #[test]
+        fn par_union_matches_serial_union() {
+            let a: RangeSet<i32> = (0..200).step_by(4).map(|n| n..n + 2).collect();
+            let b: RangeSet<i32> = (0..200).step_by(6).map(|n| n..n + 2).collect();
+
+            assert_eq!(a.par_union(&b).ranges(), a.union(&b).ranges());
+        }
+
+        #[test]
+        fn par_intersection_matches_serial_intersection() {
+            let a: RangeSet<i32> = (0..200).step_by(4).map(|n| n..n + 3).collect();
+            let b: RangeSet<i32> = (0..200).step_by(6).map(|n| n..n + 3).collect();
+
+            assert_eq!(
+                a.par_intersection(&b).ranges(),
+                a.intersection(&b).ranges()
+            );
+        }
+    }
+}